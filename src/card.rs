@@ -0,0 +1,178 @@
+use std::fmt;
+
+use rand::{
+    distributions::{Distribution, Standard},
+    seq::SliceRandom,
+    Rng,
+};
+use serde::{Deserialize, Serialize};
+use termion::color::{self, Fg};
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Color {
+    Red,
+    Blue,
+    Green,
+    Yellow,
+}
+pub const COLORS: [Color; 4] = [Color::Red, Color::Blue, Color::Green, Color::Yellow];
+
+fn ansi_rgb(color: Color) -> color::Rgb {
+    match color {
+        Color::Red => color::Rgb(220, 50, 47),
+        Color::Blue => color::Rgb(38, 139, 210),
+        Color::Green => color::Rgb(133, 153, 0),
+        Color::Yellow => color::Rgb(181, 137, 0),
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Color::Red => "Red",
+            Color::Blue => "Blue",
+            Color::Green => "Green",
+            Color::Yellow => "Yellow",
+        };
+        write!(f, "{}{label}{}", Fg(ansi_rgb(*self)), Fg(color::Reset))
+    }
+}
+
+// https://stackoverflow.com/a/48491021
+impl Distribution<Color> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Color {
+        match rng.gen_range(0..=3) {
+            0 => Color::Red,
+            1 => Color::Blue,
+            2 => Color::Yellow,
+            _ => Color::Green, // 3
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Action {
+    Draw2,
+    Reverse,
+    Skip,
+}
+pub const ACTIONS: [Action; 3] = [Action::Draw2, Action::Reverse, Action::Skip];
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Action::Draw2 => "Draw Two",
+            Action::Reverse => "Reverse",
+            Action::Skip => "Skip",
+        };
+        write!(f, "{label}")
+    }
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WildAction {
+    ChangeColor,
+    Draw4,
+}
+pub const WILD_ACTIONS: [WildAction; 2] = [WildAction::ChangeColor, WildAction::Draw4];
+
+impl fmt::Display for WildAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            WildAction::ChangeColor => "Wild",
+            WildAction::Draw4 => "Wild Draw Four",
+        };
+        write!(f, "{label}")
+    }
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Card {
+    Number { number: u8, color: Color },
+    Action { action: Action, color: Color },
+    Wild(WildAction),
+}
+
+impl Card {
+    /// This card's color, or `None` for a wild that hasn't been colored.
+    pub fn color(&self) -> Option<Color> {
+        match self {
+            Card::Number { color, .. } | Card::Action { color, .. } => Some(*color),
+            Card::Wild(_) => None,
+        }
+    }
+
+    pub fn accepts(&self, other: &Card, wild_color: Option<Color>) -> bool {
+        match [self, other] {
+            [Card::Number { color, number }, Card::Number {
+                color: other_color,
+                number: other_number,
+            }] => color == other_color || number == other_number,
+
+            [Card::Number { color, .. }, Card::Action {
+                color: other_color, ..
+            }]
+            | [Card::Action { color, .. }, Card::Number {
+                color: other_color, ..
+            }] => color == other_color,
+
+            [Card::Action { color, action }, Card::Action {
+                action: other_action,
+                color: other_color,
+            }] => color == other_color || action == other_action,
+
+            [_, Card::Wild(_)] => true,
+
+            [Card::Wild(_), Card::Number { number: _, color } | Card::Action { action: _, color }] => {
+                *color == wild_color.unwrap()
+            }
+        }
+    }
+}
+
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Card::Number { number, color } => {
+                write!(f, "{}{number}{}", Fg(ansi_rgb(*color)), Fg(color::Reset))
+            }
+            Card::Action { action, color } => {
+                write!(f, "{}{action}{}", Fg(ansi_rgb(*color)), Fg(color::Reset))
+            }
+            Card::Wild(wild_action) => write!(f, "{wild_action}"),
+        }
+    }
+}
+
+/// The 108 cards of a standard UNO deck, in a fixed (unshuffled) order.
+pub fn full_deck() -> Vec<Card> {
+    let mut deck = Vec::with_capacity(112);
+
+    for color in COLORS {
+        deck.push(Card::Number { number: 0, color });
+
+        for _ in 0..2 {
+            for number in 1..=9 {
+                deck.push(Card::Number { number, color });
+            }
+
+            for action in ACTIONS {
+                deck.push(Card::Action { action, color });
+            }
+        }
+    }
+    for wild_action in WILD_ACTIONS {
+        for _ in 0..4 {
+            deck.push(Card::Wild(wild_action));
+        }
+    }
+
+    deck
+}
+
+pub fn gen_draw_pile<R: Rng + ?Sized>(rng: &mut R) -> Vec<Card> {
+    let mut draw_pile = full_deck();
+
+    draw_pile.shuffle(rng);
+    draw_pile
+}
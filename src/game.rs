@@ -0,0 +1,726 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::card::{gen_draw_pile, Action, Card, Color, WildAction, COLORS};
+use crate::rules::Rules;
+
+pub const MAX_BOTS: u8 = 9;
+pub const INITIAL_CARDS_PER_PLAYER: u8 = 7;
+
+pub enum PlayResult {
+    Win,
+    Place,
+    NoPlace,
+    Starvation,
+}
+
+/// A single atomic action a player can take on their turn. The engine is
+/// driven purely through these so that bots, a human, and an AI all go
+/// through the same `GameState::apply` (or `GameState::jump_in` for a
+/// `JumpIn`), and so a game's move stream can be logged and replayed
+/// verbatim.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum Move {
+    /// Play the card at this index in the current player's deck.
+    PlayCard(usize),
+    /// Draw a card from the pile, auto-playing it if it turns out to be
+    /// playable and `Rules::must_play_drawn` is set.
+    DrawThenPlay,
+    /// Take no action this turn (used when a consequential card forces a
+    /// skip, or to decline playing a drawn/jumped-in card).
+    Pass,
+    /// Pick the new active color after playing a wild card.
+    ChooseWild(Color),
+    /// Play the card just drawn that turned out to be playable, offered
+    /// instead of forced when `Rules::must_play_drawn` is off.
+    PlayDrawn,
+    /// Challenge a pending Wild Draw Four instead of drawing, offered when
+    /// `Rules::draw4_challenge` is on.
+    ChallengeDraw4,
+    /// Swap hands with this opponent after playing a 7, offered when
+    /// `Rules::seven_zero` is on.
+    SwapWith(usize),
+    /// Jump in out of turn with the identical card at this index in the
+    /// jumping player's deck, offered when `Rules::jump_in` is on.
+    JumpIn(usize),
+}
+
+/// Who played a still-unresolved Wild Draw Four, and which color was in
+/// play immediately before they played it, so a challenge can later check
+/// whether that play was legal.
+#[derive(Clone, Copy)]
+struct PendingDraw4 {
+    accused: usize,
+    prior_color: Color,
+}
+
+/// Owns everything needed to play a game of UNO: the piles, every player's
+/// deck, whose turn it is, and the active house rules. `legal_moves`
+/// enumerates what the player on turn may do, and `apply` (plus `jump_in`
+/// for out-of-turn plays and `advance_turn` between turns) mutates state,
+/// so the engine can be driven identically by a human, a random bot, or a
+/// search-based bot.
+#[derive(Clone)]
+pub struct GameState {
+    pub draw_pile: Vec<Card>,
+    pub discard_pile: Vec<Card>,
+    pub decks: Vec<Vec<Card>>,
+    pub dir: i8,
+    /// Was the top card put down by the previous player, i.e. is there a
+    /// consequential card (Skip/Draw2/Draw4) still to be resolved?
+    pub is_hot: bool,
+    pub wild_color: Option<Color>,
+    pub cur_idx: usize,
+    pub rules: Rules,
+    /// Set once a wild card has been played but no color has been chosen
+    /// for it yet; the only legal moves in this state are `ChooseWild`.
+    awaiting_wild_color: bool,
+    /// Set once a 7 has been played under `Rules::seven_zero` but no swap
+    /// target has been chosen yet; the only legal moves are `SwapWith`.
+    awaiting_swap_target: bool,
+    /// Index of a just-drawn, playable card still awaiting the player's
+    /// choice to play it or keep it, under a non-forcing `must_play_drawn`.
+    awaiting_drawn_play: Option<usize>,
+    /// Accumulated draw owed once a Draw2/Draw4 stack under
+    /// `Rules::stacking` stops being extended.
+    pending_draw: u8,
+    /// The still-unresolved Wild Draw Four a `Rules::draw4_challenge` may
+    /// be raised against.
+    pending_draw4: Option<PendingDraw4>,
+}
+
+impl GameState {
+    pub fn new<R: Rng + ?Sized>(bot_count: u8, rng: &mut R, rules: Rules) -> Self {
+        let mut draw_pile = gen_draw_pile(rng);
+        let mut discard_pile = Vec::new();
+        let mut decks = Vec::new();
+
+        for _ in 0..=bot_count {
+            let mut deck = Vec::new();
+            transfer_cards(
+                &mut draw_pile,
+                &mut discard_pile,
+                &mut deck,
+                INITIAL_CARDS_PER_PLAYER,
+                rng,
+            );
+            decks.push(deck);
+        }
+
+        init_discard_pile(&mut discard_pile, &mut draw_pile, rng);
+
+        GameState {
+            draw_pile,
+            discard_pile,
+            decks,
+            dir: 1,
+            is_hot: true,
+            wild_color: None,
+            cur_idx: 0,
+            rules,
+            awaiting_wild_color: false,
+            awaiting_swap_target: false,
+            awaiting_drawn_play: None,
+            pending_draw: 0,
+            pending_draw4: None,
+        }
+    }
+
+    pub fn player_count(&self) -> usize {
+        self.decks.len()
+    }
+
+    pub fn top_card(&self) -> &Card {
+        self.discard_pile.last().unwrap()
+    }
+
+    /// The color a candidate card must match right now: the chosen color
+    /// of an uncolored wild on top, or that top card's own color.
+    fn active_color(&self) -> Color {
+        self.wild_color
+            .unwrap_or_else(|| self.top_card().color().expect("wild_color is set while a wild is on top"))
+    }
+
+    /// Enumerate the moves available to the player on turn. Always
+    /// non-empty: a forced skip/draw or a plain draw is offered when no
+    /// card can be played.
+    pub fn legal_moves(&self, player_idx: usize) -> Vec<Move> {
+        if self.awaiting_wild_color {
+            return COLORS.iter().copied().map(Move::ChooseWild).collect();
+        }
+
+        if self.awaiting_swap_target {
+            return (0..self.player_count())
+                .filter(|&seat| seat != player_idx)
+                .map(Move::SwapWith)
+                .collect();
+        }
+
+        if self.awaiting_drawn_play.is_some() {
+            return vec![Move::PlayDrawn, Move::Pass];
+        }
+
+        if self.is_hot {
+            match self.top_card() {
+                Card::Action {
+                    action: Action::Skip,
+                    ..
+                } => return vec![Move::Pass],
+
+                Card::Action {
+                    action: Action::Draw2,
+                    ..
+                } => {
+                    let mut moves = self.stack_moves(player_idx, |card| {
+                        matches!(
+                            card,
+                            Card::Action {
+                                action: Action::Draw2,
+                                ..
+                            }
+                        )
+                    });
+                    moves.push(Move::DrawThenPlay);
+                    return moves;
+                }
+
+                Card::Wild(WildAction::Draw4) => {
+                    let mut moves =
+                        self.stack_moves(player_idx, |card| matches!(card, Card::Wild(WildAction::Draw4)));
+                    if self.rules.draw4_challenge && self.pending_draw4.is_some() {
+                        moves.push(Move::ChallengeDraw4);
+                    }
+                    moves.push(Move::DrawThenPlay);
+                    return moves;
+                }
+
+                _ => {}
+            }
+        }
+
+        let top = self.top_card();
+        let mut moves: Vec<Move> = self.decks[player_idx]
+            .iter()
+            .enumerate()
+            .filter(|(_, card)| top.accepts(card, self.wild_color))
+            .map(|(idx, _)| Move::PlayCard(idx))
+            .collect();
+
+        if moves.is_empty() {
+            moves.push(Move::DrawThenPlay);
+        }
+
+        moves
+    }
+
+    /// The cards in `player_idx`'s hand matching `is_stackable`, as
+    /// `PlayCard` moves, or empty when `Rules::stacking` is off.
+    fn stack_moves(&self, player_idx: usize, is_stackable: impl Fn(&Card) -> bool) -> Vec<Move> {
+        if !self.rules.stacking {
+            return Vec::new();
+        }
+
+        self.decks[player_idx]
+            .iter()
+            .enumerate()
+            .filter(|(_, card)| is_stackable(card))
+            .map(|(idx, _)| Move::PlayCard(idx))
+            .collect()
+    }
+
+    /// Seats other than `exclude` currently holding a card identical to the
+    /// top of the discard pile, paired with that card's index in their
+    /// hand. Only meaningful when `Rules::jump_in` is on, and only while no
+    /// follow-up decision (color, swap, drawn-card) is outstanding.
+    pub fn jump_in_candidates(&self, exclude: usize) -> Vec<(usize, usize)> {
+        if self.awaiting_followup() {
+            return Vec::new();
+        }
+
+        let top = self.top_card();
+        (0..self.player_count())
+            .filter(|&seat| seat != exclude)
+            .filter_map(|seat| {
+                self.decks[seat]
+                    .iter()
+                    .position(|card| card == top)
+                    .map(|idx| (seat, idx))
+            })
+            .collect()
+    }
+
+    /// Apply a move for the player currently on turn, mutating piles,
+    /// decks and turn order in place.
+    pub fn apply<R: Rng + ?Sized>(&mut self, mv: Move, rng: &mut R) -> PlayResult {
+        let player_idx = self.cur_idx;
+
+        match mv {
+            Move::ChooseWild(color) => {
+                self.awaiting_wild_color = false;
+                self.wild_color = Some(color);
+                self.is_hot = true;
+                PlayResult::Place
+            }
+
+            Move::SwapWith(target) => {
+                self.awaiting_swap_target = false;
+                self.decks.swap(player_idx, target);
+                self.wild_color = None;
+                self.is_hot = true;
+                PlayResult::Place
+            }
+
+            Move::PlayDrawn => {
+                let idx = self
+                    .awaiting_drawn_play
+                    .take()
+                    .expect("PlayDrawn only legal while a drawn card awaits a decision");
+                self.play_card(player_idx, idx)
+            }
+
+            Move::Pass => {
+                // A forced Skip, a declined drawn card, or a declined
+                // auto-play of a just-drawn card.
+                self.awaiting_drawn_play = None;
+                self.is_hot = false;
+                PlayResult::NoPlace
+            }
+
+            Move::ChallengeDraw4 => self.resolve_challenge(player_idx, rng),
+
+            Move::DrawThenPlay if self.is_hot && self.forced_draw_pending() => {
+                self.resolve_forced_draw(player_idx, rng)
+            }
+
+            Move::DrawThenPlay => {
+                if transfer_cards(
+                    &mut self.draw_pile,
+                    &mut self.discard_pile,
+                    &mut self.decks[player_idx],
+                    1,
+                    rng,
+                ) {
+                    return PlayResult::Starvation;
+                }
+                let idx = self.decks[player_idx].len() - 1;
+                let drawn = &self.decks[player_idx][idx];
+                if self.top_card().accepts(drawn, self.wild_color) {
+                    if self.rules.must_play_drawn {
+                        return self.play_card(player_idx, idx);
+                    }
+                    self.awaiting_drawn_play = Some(idx);
+                    return PlayResult::NoPlace;
+                }
+                self.is_hot = false;
+                PlayResult::NoPlace
+            }
+
+            Move::PlayCard(idx) => self.play_card(player_idx, idx),
+
+            Move::JumpIn(_) => {
+                unreachable!("jump-in is resolved via GameState::jump_in, not apply")
+            }
+        }
+    }
+
+    /// Resolve `seat` interrupting out of turn with the identical card at
+    /// `idx` in their hand, taking over as the player on turn.
+    pub fn jump_in(&mut self, seat: usize, idx: usize) -> PlayResult {
+        self.cur_idx = seat;
+        self.play_card(seat, idx)
+    }
+
+    /// Resolve a challenge against the pending Wild Draw Four: the accused
+    /// player draws the penalty instead of `challenger` if they were
+    /// bluffing (holding a card matching the color in play beforehand),
+    /// otherwise the challenger draws it.
+    fn resolve_challenge<R: Rng + ?Sized>(&mut self, challenger: usize, rng: &mut R) -> PlayResult {
+        let PendingDraw4 { accused, prior_color } = self
+            .pending_draw4
+            .take()
+            .expect("ChallengeDraw4 only legal while a draw-four is pending");
+        self.is_hot = false;
+
+        let bluffed = self.decks[accused].iter().any(|card| card.color() == Some(prior_color));
+        let loser = if bluffed { accused } else { challenger };
+
+        if transfer_cards(&mut self.draw_pile, &mut self.discard_pile, &mut self.decks[loser], 4, rng) {
+            return PlayResult::Starvation;
+        }
+        PlayResult::NoPlace
+    }
+
+    /// Whether `top_card` is a Draw2/Draw4 that hasn't been stacked past or
+    /// declined yet, i.e. whether a `DrawThenPlay` right now is the forced
+    /// penalty rather than an ordinary empty-handed draw. `is_hot` alone
+    /// isn't enough: it just means "freshly played", which is also true
+    /// right after a plain card with nothing left to draw for.
+    fn forced_draw_pending(&self) -> bool {
+        Self::stack_value(self.top_card()) > 0
+    }
+
+    /// How many cards `card` forces onto whoever it's stacked onto or
+    /// played against: 2 for a Draw Two, 4 for a Wild Draw Four, 0 for
+    /// anything else.
+    fn stack_value(card: &Card) -> u8 {
+        match card {
+            Card::Action {
+                action: Action::Draw2,
+                ..
+            } => 2,
+            Card::Wild(WildAction::Draw4) => 4,
+            _ => 0,
+        }
+    }
+
+    /// Resolve a declined (or stacked-past) Draw2/Draw4: draw the
+    /// accumulated stack if `Rules::stacking` built one up, else the base
+    /// amount for whichever card is on top.
+    fn resolve_forced_draw<R: Rng + ?Sized>(&mut self, player_idx: usize, rng: &mut R) -> PlayResult {
+        let amount = if self.rules.stacking && self.pending_draw > 0 {
+            self.pending_draw
+        } else {
+            let value = Self::stack_value(self.top_card());
+            assert!(value > 0, "DrawThenPlay only forced by Draw2/Draw4");
+            value
+        };
+        self.pending_draw = 0;
+        self.pending_draw4 = None;
+
+        if transfer_cards(&mut self.draw_pile, &mut self.discard_pile, &mut self.decks[player_idx], amount, rng) {
+            return PlayResult::Starvation;
+        }
+        self.is_hot = false;
+        PlayResult::NoPlace
+    }
+
+    fn play_card(&mut self, player_idx: usize, idx: usize) -> PlayResult {
+        let prior_color = self.active_color();
+
+        // A stacking card always matches the type it's stacked onto (see
+        // `stack_moves`), so the card being stacked onto and the card just
+        // played are worth the same amount. Read that off the pre-play top
+        // before it's replaced by the card we're about to push.
+        let stack_bonus = if self.rules.stacking && self.is_hot && self.forced_draw_pending() {
+            Self::stack_value(self.top_card())
+        } else {
+            0
+        };
+
+        self.discard_pile.push(self.decks[player_idx].swap_remove(idx));
+        self.pending_draw4 = None;
+
+        if self.decks[player_idx].is_empty() {
+            return PlayResult::Win;
+        }
+
+        if stack_bonus > 0 {
+            if self.pending_draw == 0 {
+                // Nobody's credited the card being stacked onto yet; seed
+                // the pile with it before adding this card's own stack.
+                self.pending_draw += stack_bonus;
+            }
+            self.pending_draw += stack_bonus;
+        }
+
+        match self.top_card() {
+            Card::Action {
+                action: Action::Reverse,
+                ..
+            } => {
+                self.dir = -self.dir;
+            }
+
+            Card::Wild(wild_action) => {
+                if matches!(wild_action, WildAction::Draw4) {
+                    self.pending_draw4 = Some(PendingDraw4 {
+                        accused: player_idx,
+                        prior_color,
+                    });
+                }
+                self.awaiting_wild_color = true;
+                return PlayResult::NoPlace;
+            }
+
+            Card::Number { number: 7, .. } if self.rules.seven_zero => {
+                self.awaiting_swap_target = true;
+                return PlayResult::NoPlace;
+            }
+
+            Card::Number { number: 0, .. } if self.rules.seven_zero => {
+                self.rotate_hands();
+            }
+
+            _ => {}
+        }
+
+        self.wild_color = None;
+        self.is_hot = true;
+        PlayResult::Place
+    }
+
+    /// Shift every hand one seat in the direction of play (`Rules::seven_zero`'s 0 rule).
+    fn rotate_hands(&mut self) {
+        let player_count = i32::try_from(self.decks.len()).unwrap();
+        let dir = i32::from(self.dir);
+
+        let mut rotated = vec![Vec::new(); self.decks.len()];
+        for (seat, deck) in self.decks.iter_mut().enumerate() {
+            let seat = i32::try_from(seat).unwrap();
+            let target = usize::try_from((seat + dir).rem_euclid(player_count)).unwrap();
+            rotated[target] = std::mem::take(deck);
+        }
+        self.decks = rotated;
+    }
+
+    /// Whether the player on turn still owes a follow-up decision (a wild
+    /// color, a swap target, or what to do with a just-drawn card) before
+    /// the turn can advance.
+    pub fn awaiting_followup(&self) -> bool {
+        self.awaiting_wild_color || self.awaiting_swap_target || self.awaiting_drawn_play.is_some()
+    }
+
+    /// Advance `cur_idx` in the current direction.
+    pub fn advance_turn(&mut self) {
+        let player_count = i8::try_from(self.player_count()).unwrap();
+        self.cur_idx = (self.cur_idx + usize::try_from(player_count + self.dir).unwrap())
+            % self.decks.len();
+    }
+}
+
+pub fn transfer_cards<R: Rng + ?Sized>(
+    draw_pile: &mut Vec<Card>,
+    discard_pile: &mut Vec<Card>,
+    deck: &mut Vec<Card>,
+    amount: u8,
+    rng: &mut R,
+) -> bool {
+    for _ in 0..amount {
+        loop {
+            if let Some(card) = draw_pile.pop() {
+                deck.push(card);
+                break;
+            }
+
+            // Discard pile size should never be 0 since there'll always be
+            // a top card.
+            if discard_pile.len() == 1 {
+                // There are no more cards left to play with.
+                return true;
+            }
+            for card in discard_pile.drain(..discard_pile.len() - 1) {
+                draw_pile.push(card);
+            }
+            draw_pile.shuffle(rng);
+        }
+    }
+
+    false
+}
+
+pub fn init_discard_pile<R: Rng + ?Sized>(discard_pile: &mut Vec<Card>, draw_pile: &mut Vec<Card>, rng: &mut R) {
+    while matches!(draw_pile.last().unwrap(), Card::Wild(_)) {
+        draw_pile.shuffle(rng);
+    }
+    discard_pile.push(draw_pile.pop().unwrap());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::Color;
+
+    fn draw2(color: Color) -> Card {
+        Card::Action {
+            action: Action::Draw2,
+            color,
+        }
+    }
+
+    fn number(number: u8, color: Color) -> Card {
+        Card::Number { number, color }
+    }
+
+    /// A two-seat game, hot on a Draw2, with a second Draw2 in each seat's
+    /// hand ready to stack — everything `apply`/`jump_in` need and nothing
+    /// else, so the stacking math under test isn't entangled with dealing.
+    fn hot_on_draw2(rules: Rules) -> GameState {
+        GameState {
+            draw_pile: Vec::new(),
+            discard_pile: vec![draw2(Color::Red)],
+            decks: vec![
+                vec![Card::Number { number: 1, color: Color::Red }, draw2(Color::Blue)],
+                vec![Card::Number { number: 1, color: Color::Red }, draw2(Color::Green)],
+            ],
+            dir: 1,
+            is_hot: true,
+            wild_color: None,
+            cur_idx: 0,
+            rules,
+            awaiting_wild_color: false,
+            awaiting_swap_target: false,
+            awaiting_drawn_play: None,
+            pending_draw: 0,
+            pending_draw4: None,
+        }
+    }
+
+    #[test]
+    fn stacking_a_draw2_credits_both_the_prior_and_new_card() {
+        let rules = Rules {
+            stacking: true,
+            ..Rules::default()
+        };
+        let mut game = hot_on_draw2(rules);
+        let mut rng = rand::thread_rng();
+
+        game.apply(Move::PlayCard(1), &mut rng);
+
+        assert_eq!(game.pending_draw, 4, "two stacked Draw2s should owe 4, not 2");
+    }
+
+    #[test]
+    fn a_third_stacked_draw2_only_adds_its_own_value() {
+        let rules = Rules {
+            stacking: true,
+            ..Rules::default()
+        };
+        let mut game = hot_on_draw2(rules);
+        let mut rng = rand::thread_rng();
+
+        game.apply(Move::PlayCard(1), &mut rng);
+        game.cur_idx = 1;
+        game.decks[1].push(draw2(Color::Yellow));
+        let idx = game.decks[1].len() - 1;
+        game.apply(Move::PlayCard(idx), &mut rng);
+
+        assert_eq!(game.pending_draw, 6, "three stacked Draw2s should owe 6");
+    }
+
+    #[test]
+    fn jumping_in_with_a_stacking_draw2_credits_both_cards() {
+        let rules = Rules {
+            stacking: true,
+            jump_in: true,
+            ..Rules::default()
+        };
+        let mut game = hot_on_draw2(rules);
+
+        game.jump_in(1, 1);
+
+        assert_eq!(
+            game.pending_draw, 4,
+            "jumping in with a stacker should accumulate the same as playing it in turn"
+        );
+    }
+
+    #[test]
+    fn must_play_drawn_forces_an_immediate_play() {
+        let rules = Rules {
+            must_play_drawn: true,
+            ..Rules::default()
+        };
+        let mut game = GameState {
+            draw_pile: vec![number(5, Color::Blue)],
+            discard_pile: vec![number(5, Color::Red)],
+            decks: vec![vec![number(1, Color::Blue)], vec![number(2, Color::Blue)]],
+            dir: 1,
+            is_hot: false,
+            wild_color: None,
+            cur_idx: 0,
+            rules,
+            awaiting_wild_color: false,
+            awaiting_swap_target: false,
+            awaiting_drawn_play: None,
+            pending_draw: 0,
+            pending_draw4: None,
+        };
+        let mut rng = rand::thread_rng();
+
+        let result = game.apply(Move::DrawThenPlay, &mut rng);
+
+        assert!(matches!(result, PlayResult::Place));
+        assert_eq!(game.discard_pile.last(), Some(&number(5, Color::Blue)));
+        assert!(!game.awaiting_followup(), "a forced play leaves nothing to decide");
+        assert_eq!(game.decks[0].len(), 1, "the drawn card should be played, not kept");
+    }
+
+    #[test]
+    fn draw4_challenge_charges_the_accused_when_they_bluffed() {
+        let rules = Rules {
+            draw4_challenge: true,
+            ..Rules::default()
+        };
+        let mut game = GameState {
+            draw_pile: vec![number(1, Color::Green); 4],
+            discard_pile: vec![Card::Wild(WildAction::Draw4)],
+            decks: vec![
+                vec![number(2, Color::Red), number(3, Color::Blue)],
+                vec![number(4, Color::Green)],
+            ],
+            dir: 1,
+            is_hot: true,
+            wild_color: Some(Color::Blue),
+            cur_idx: 1,
+            rules,
+            awaiting_wild_color: false,
+            awaiting_swap_target: false,
+            awaiting_drawn_play: None,
+            pending_draw: 0,
+            pending_draw4: Some(PendingDraw4 {
+                accused: 0,
+                prior_color: Color::Red,
+            }),
+        };
+        let mut rng = rand::thread_rng();
+
+        let result = game.apply(Move::ChallengeDraw4, &mut rng);
+
+        assert!(matches!(result, PlayResult::NoPlace));
+        assert_eq!(
+            game.decks[0].len(),
+            6,
+            "the accused was holding a red card, so they were bluffing and draw the penalty"
+        );
+        assert_eq!(game.decks[1].len(), 1, "the challenger was right, so they draw nothing");
+        assert!(game.pending_draw4.is_none());
+    }
+
+    #[test]
+    fn draw4_challenge_charges_the_challenger_when_the_play_was_legal() {
+        let rules = Rules {
+            draw4_challenge: true,
+            ..Rules::default()
+        };
+        let mut game = GameState {
+            draw_pile: vec![number(1, Color::Green); 4],
+            discard_pile: vec![Card::Wild(WildAction::Draw4)],
+            decks: vec![vec![number(2, Color::Blue)], vec![number(4, Color::Green)]],
+            dir: 1,
+            is_hot: true,
+            wild_color: Some(Color::Blue),
+            cur_idx: 1,
+            rules,
+            awaiting_wild_color: false,
+            awaiting_swap_target: false,
+            awaiting_drawn_play: None,
+            pending_draw: 0,
+            pending_draw4: Some(PendingDraw4 {
+                accused: 0,
+                prior_color: Color::Red,
+            }),
+        };
+        let mut rng = rand::thread_rng();
+
+        let result = game.apply(Move::ChallengeDraw4, &mut rng);
+
+        assert!(matches!(result, PlayResult::NoPlace));
+        assert_eq!(game.decks[0].len(), 1, "the accused held nothing matching red, so the play was legal");
+        assert_eq!(
+            game.decks[1].len(),
+            5,
+            "the challenge was wrong, so the challenger draws the penalty"
+        );
+    }
+}
@@ -0,0 +1,180 @@
+use std::io::{self, Write};
+
+use termion::{clear, cursor};
+
+use crate::card::{Action, Card, Color, WildAction, COLORS};
+use crate::game::{GameState, Move};
+use crate::observer::Observer;
+use crate::player::Player;
+
+pub struct Human;
+
+impl Player for Human {
+    fn choose_move(
+        &mut self,
+        game: &GameState,
+        player_idx: usize,
+        moves: &[Move],
+        _observer: &mut dyn Observer,
+    ) -> Move {
+        if let [Move::JumpIn(idx), Move::Pass] = moves {
+            return ask_jump_in(game, player_idx, *idx);
+        }
+
+        render(game, player_idx);
+
+        if moves.len() == 1 {
+            match moves[0] {
+                Move::Pass => println!("You're skipped this turn."),
+                Move::DrawThenPlay
+                    if game.is_hot
+                        && matches!(
+                            game.top_card(),
+                            Card::Action {
+                                action: Action::Draw2,
+                                ..
+                            } | Card::Wild(WildAction::Draw4)
+                        ) =>
+                {
+                    println!("You must draw the penalty cards.");
+                }
+                Move::DrawThenPlay => println!("Nothing playable, drawing a card..."),
+                _ => {}
+            }
+            wait_for_enter();
+            return moves[0];
+        }
+
+        if matches!(moves[0], Move::ChooseWild(_)) {
+            return Move::ChooseWild(ask_wild_color());
+        }
+
+        if matches!(moves[0], Move::SwapWith(_)) {
+            return ask_swap_target(moves);
+        }
+
+        if let [Move::PlayDrawn, Move::Pass] = moves {
+            return ask_play_drawn(game, player_idx);
+        }
+
+        ask_move(game, player_idx, moves)
+    }
+}
+
+fn render(game: &GameState, player_idx: usize) {
+    print!("{}{}", clear::All, cursor::Goto(1, 1));
+    println!("Top of discard pile: {}", game.top_card());
+    if let Some(color) = game.wild_color {
+        println!("Current colour: {color}");
+    }
+    println!("Your hand:");
+    for (idx, card) in game.decks[player_idx].iter().enumerate() {
+        println!("  [{idx}] {card}");
+    }
+    io::stdout().flush().unwrap();
+}
+
+fn wait_for_enter() {
+    println!("Press enter to continue.");
+    let mut buf = String::new();
+    io::stdin().read_line(&mut buf).unwrap();
+}
+
+fn ask_wild_color() -> Color {
+    loop {
+        println!("Choose a colour:");
+        for (idx, color) in COLORS.iter().enumerate() {
+            println!("  [{idx}] {color}");
+        }
+
+        let mut buf = String::new();
+        io::stdin().read_line(&mut buf).unwrap();
+        if let Ok(idx) = buf.trim().parse::<usize>() {
+            if let Some(color) = COLORS.get(idx) {
+                return *color;
+            }
+        }
+        println!("Not a valid colour, try again.");
+    }
+}
+
+fn ask_move(game: &GameState, player_idx: usize, moves: &[Move]) -> Move {
+    let can_challenge = moves.iter().any(|mv| matches!(mv, Move::ChallengeDraw4));
+
+    loop {
+        print!("Enter a card index to play, \"draw\" to draw a card");
+        if can_challenge {
+            print!(", or \"challenge\" to challenge the Wild Draw Four");
+        }
+        println!(":");
+        io::stdout().flush().unwrap();
+
+        let mut buf = String::new();
+        io::stdin().read_line(&mut buf).unwrap();
+        let input = buf.trim();
+
+        if input.eq_ignore_ascii_case("draw") {
+            if let Some(mv) = moves.iter().find(|mv| matches!(mv, Move::DrawThenPlay)) {
+                return *mv;
+            }
+        } else if can_challenge && input.eq_ignore_ascii_case("challenge") {
+            return Move::ChallengeDraw4;
+        } else if let Ok(idx) = input.parse::<usize>() {
+            if let Some(mv) = moves
+                .iter()
+                .find(|mv| matches!(mv, Move::PlayCard(card_idx) if *card_idx == idx))
+            {
+                return *mv;
+            }
+        }
+
+        println!("That's not playable right now. Try again.");
+        render(game, player_idx);
+    }
+}
+
+fn ask_swap_target(moves: &[Move]) -> Move {
+    loop {
+        println!("Choose a player to swap hands with:");
+        for mv in moves {
+            if let Move::SwapWith(seat) = mv {
+                println!("  [{seat}] Bot {seat}");
+            }
+        }
+
+        let mut buf = String::new();
+        io::stdin().read_line(&mut buf).unwrap();
+        if let Ok(seat) = buf.trim().parse::<usize>() {
+            if let Some(mv) = moves.iter().find(|mv| matches!(mv, Move::SwapWith(s) if *s == seat)) {
+                return *mv;
+            }
+        }
+        println!("Not a valid player, try again.");
+    }
+}
+
+fn ask_play_drawn(game: &GameState, player_idx: usize) -> Move {
+    let drawn = game.decks[player_idx].last().unwrap();
+    println!("You drew a {drawn}, which is playable. Play it? y/n");
+
+    let mut buf = String::new();
+    io::stdin().read_line(&mut buf).unwrap();
+    if buf.trim().eq_ignore_ascii_case("y") {
+        Move::PlayDrawn
+    } else {
+        Move::Pass
+    }
+}
+
+fn ask_jump_in(game: &GameState, player_idx: usize, idx: usize) -> Move {
+    let card = &game.decks[player_idx][idx];
+    println!("You can jump in with your {card} onto {}. Play it? y/n", game.top_card());
+
+    let mut buf = String::new();
+    io::stdin().read_line(&mut buf).unwrap();
+    if buf.trim().eq_ignore_ascii_case("y") {
+        Move::JumpIn(idx)
+    } else {
+        Move::Pass
+    }
+}
@@ -0,0 +1,225 @@
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter};
+use std::path::Path;
+
+use rand::{rngs::StdRng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use crate::game::{GameState, Move, PlayResult, MAX_BOTS};
+use crate::rules::Rules;
+
+/// One player's turn as it actually happened: who acted and what they did.
+/// Recorded in order so a game can be replayed move-for-move without
+/// re-running any player's decision logic.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct LoggedMove {
+    pub player: usize,
+    pub mv: Move,
+}
+
+/// How a recorded game ended. Mirrors the terminal cases of `PlayResult`;
+/// `Place`/`NoPlace` never reach the log since they're mid-game.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LoggedResult {
+    Win { winner: usize },
+    Starvation,
+}
+
+/// Everything needed to reproduce a finished game exactly: the seed that
+/// drove its shuffle and every subsequent draw, the rules it was played
+/// under, each player's starting hand, the full move stream, and how it
+/// ended.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GameLog {
+    pub seed: u64,
+    pub rules: Rules,
+    pub starting_hands: Vec<Vec<crate::card::Card>>,
+    pub moves: Vec<LoggedMove>,
+    pub result: LoggedResult,
+}
+
+impl GameLog {
+    pub fn write(&self, path: &Path) -> io::Result<()> {
+        let file = File::create(path)?;
+        serde_json::to_writer_pretty(BufWriter::new(file), self).map_err(io::Error::other)
+    }
+
+    pub fn read(path: &Path) -> io::Result<Self> {
+        let file = File::open(path)?;
+        serde_json::from_reader(BufReader::new(file)).map_err(io::Error::other)
+    }
+}
+
+/// Re-run a logged game from its seed, applying exactly the recorded move
+/// stream instead of asking any player, and checking every played or
+/// jumped-in card still validates against `Card::accepts` at the point
+/// it's played. Returns a description of the first place replay diverges
+/// from the log, if any.
+pub fn replay(log: &GameLog) -> Result<(), String> {
+    let mut rng = StdRng::seed_from_u64(log.seed);
+    let seat_count = log.starting_hands.len();
+    if seat_count == 0 || seat_count > usize::from(MAX_BOTS) + 1 {
+        return Err(format!(
+            "starting_hands has {seat_count} entries, expected 1..={}",
+            usize::from(MAX_BOTS) + 1
+        ));
+    }
+    let bot_count = u8::try_from(seat_count - 1).expect("bounded above by MAX_BOTS");
+    let mut game = GameState::new(bot_count, &mut rng, log.rules);
+
+    if game.decks != log.starting_hands {
+        return Err("the seed didn't reproduce the logged starting hands".to_string());
+    }
+
+    let mut moves = log.moves.iter().copied();
+    let result = loop {
+        let Some(LoggedMove { player, mv }) = moves.next() else {
+            return Err("the move stream ended before the game reached its logged result".to_string());
+        };
+
+        validate_move(&game, player, mv)?;
+
+        let result = if let Move::JumpIn(idx) = mv {
+            game.jump_in(player, idx)
+        } else {
+            if player != game.cur_idx {
+                return Err(format!(
+                    "the log says seat {player} acted, but seat {} was on turn",
+                    game.cur_idx
+                ));
+            }
+            game.apply(mv, &mut rng)
+        };
+
+        if matches!(result, PlayResult::Win | PlayResult::Starvation) {
+            break result;
+        }
+        if !game.awaiting_followup() {
+            game.advance_turn();
+        }
+    };
+
+    match (result, log.result) {
+        (PlayResult::Win, LoggedResult::Win { winner }) if winner == game.cur_idx => Ok(()),
+        (PlayResult::Starvation, LoggedResult::Starvation) => Ok(()),
+        _ => Err("the replayed outcome didn't match the logged result".to_string()),
+    }
+}
+
+/// Check that a move about to be replayed is still legal given the live
+/// state: the card it names, if any, must still accept onto the current
+/// top of the discard pile.
+fn validate_move(game: &GameState, player: usize, mv: Move) -> Result<(), String> {
+    let card = match mv {
+        Move::PlayCard(idx) | Move::JumpIn(idx) => game.decks[player].get(idx),
+        Move::PlayDrawn => game.decks[player].last(),
+        _ => return Ok(()),
+    };
+    let Some(card) = card else {
+        return Err(format!("seat {player} has no card at the logged index"));
+    };
+    if !game.top_card().accepts(card, game.wild_color) {
+        return Err(format!(
+            "seat {player}'s {card} no longer accepts onto {}",
+            game.top_card()
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::observer::NullObserver;
+    use crate::player::{Bot, Player};
+    use crate::round::play_round;
+    use crate::rules::Rules;
+
+    /// Play a whole game with random bots under a fixed seed and capture it
+    /// as a `GameLog`, the same way `main::start` does.
+    fn play_logged_game(seed: u64, rules: Rules) -> GameLog {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let bot_count = 2;
+        let mut game = GameState::new(bot_count, &mut rng, rules);
+        let starting_hands = game.decks.clone();
+        let mut players: Vec<Box<dyn Player>> = (0..=bot_count).map(|_| Box::new(Bot) as Box<dyn Player>).collect();
+        let mut observer = NullObserver;
+        let mut moves = Vec::new();
+
+        let outcome = play_round(&mut game, &mut players, &mut rng, &mut observer, &mut moves);
+
+        let result = match outcome.result {
+            PlayResult::Win => LoggedResult::Win {
+                winner: outcome.last_player,
+            },
+            _ => LoggedResult::Starvation,
+        };
+
+        GameLog {
+            seed,
+            rules,
+            starting_hands,
+            moves,
+            result,
+        }
+    }
+
+    #[test]
+    fn write_then_read_round_trips() {
+        let log = play_logged_game(1, Rules::default());
+        let path = std::env::temp_dir().join("ein-log-write-then-read-round-trips.json");
+
+        log.write(&path).unwrap();
+        let read_back = GameLog::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(read_back.seed, log.seed);
+        assert_eq!(read_back.starting_hands, log.starting_hands);
+        assert_eq!(read_back.moves.len(), log.moves.len());
+        assert_eq!(read_back.result, log.result);
+    }
+
+    #[test]
+    fn replay_reproduces_a_logged_game() {
+        let log = play_logged_game(42, Rules::default());
+
+        assert_eq!(replay(&log), Ok(()));
+    }
+
+    #[test]
+    fn replay_rejects_an_empty_starting_hands_array_instead_of_panicking() {
+        let log = GameLog {
+            seed: 0,
+            rules: Rules::default(),
+            starting_hands: Vec::new(),
+            moves: Vec::new(),
+            result: LoggedResult::Starvation,
+        };
+
+        assert!(replay(&log).is_err());
+    }
+
+    #[test]
+    fn replay_rejects_an_oversized_starting_hands_array_instead_of_panicking() {
+        let log = GameLog {
+            seed: 0,
+            rules: Rules::default(),
+            starting_hands: vec![Vec::new(); usize::from(MAX_BOTS) + 2],
+            moves: Vec::new(),
+            result: LoggedResult::Starvation,
+        };
+
+        assert!(replay(&log).is_err());
+    }
+
+    #[test]
+    fn replay_rejects_a_truncated_move_stream() {
+        let mut log = play_logged_game(7, Rules::default());
+        log.moves.clear();
+
+        assert_eq!(
+            replay(&log),
+            Err("the move stream ended before the game reached its logged result".to_string())
+        );
+    }
+}
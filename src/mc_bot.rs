@@ -0,0 +1,301 @@
+use rand::{rngs::ThreadRng, seq::SliceRandom};
+
+use crate::card::{full_deck, Action, Card, WildAction};
+use crate::game::{GameState, Move, PlayResult};
+use crate::observer::Observer;
+use crate::player::Player;
+
+/// Default number of determinizations sampled per decision. Higher values
+/// trade speed for a more accurate win-rate estimate.
+const DEFAULT_DETERMINIZATIONS: u32 = 100;
+
+/// A determinized Monte-Carlo bot: on each turn it deals the unseen cards
+/// into plausible opponent hands and a plausible draw pile, plays out the
+/// rest of the game for every candidate move with a fast greedy policy, and
+/// picks whichever move won the most often.
+pub struct McBot {
+    determinizations: u32,
+}
+
+impl McBot {
+    pub fn new(determinizations: u32) -> Self {
+        McBot { determinizations }
+    }
+}
+
+impl Default for McBot {
+    fn default() -> Self {
+        McBot::new(DEFAULT_DETERMINIZATIONS)
+    }
+}
+
+impl Player for McBot {
+    fn choose_move(
+        &mut self,
+        game: &GameState,
+        player_idx: usize,
+        moves: &[Move],
+        observer: &mut dyn Observer,
+    ) -> Move {
+        let rng = &mut rand::thread_rng();
+
+        if moves.len() == 1 {
+            return moves[0];
+        }
+        if matches!(moves[0], Move::ChooseWild(_)) {
+            let chosen = greedy_move(game, player_idx, moves, rng);
+            if let Move::ChooseWild(color) = chosen {
+                observer.announce(&format!(
+                    "Bot {player_idx} chooses {color} as the new colour."
+                ));
+            }
+            return chosen;
+        }
+        // A jump-in offer is a binary interrupt decision, not a full turn;
+        // skip the determinization search and jump only when it's clearly
+        // disruptive, rather than pay for 100 rollouts to decide.
+        if let Move::JumpIn(idx) = moves[0] {
+            let card = &game.decks[player_idx][idx];
+            if aggression(card) > 0 {
+                observer.announce(&format!("Bot {player_idx} jumps in with a {card}."));
+                return moves[0];
+            }
+            return Move::Pass;
+        }
+
+        let mut wins = vec![0u32; moves.len()];
+        for _ in 0..self.determinizations {
+            let determinized = determinize(game, player_idx, rng);
+
+            for (i, &mv) in moves.iter().enumerate() {
+                let mut trial = determinized.clone();
+                match trial.apply(mv, rng) {
+                    PlayResult::Win => {
+                        wins[i] += 1;
+                        continue;
+                    }
+                    PlayResult::Starvation => continue,
+                    PlayResult::NoPlace | PlayResult::Place => {}
+                }
+                if !trial.awaiting_followup() {
+                    trial.advance_turn();
+                }
+                if rollout(trial, rng) == Some(player_idx) {
+                    wins[i] += 1;
+                }
+            }
+        }
+
+        let best = (0..moves.len())
+            .max_by_key(|&i| (wins[i], tie_break_score(game, player_idx, moves[i])))
+            .unwrap();
+        let chosen = moves[best];
+        match chosen {
+            Move::PlayCard(idx) => {
+                let card = &game.decks[player_idx][idx];
+                observer.announce(&format!("Bot {player_idx} plays a {card}."));
+            }
+            Move::ChallengeDraw4 => {
+                observer.announce(&format!("Bot {player_idx} challenges the Wild Draw Four."));
+            }
+            Move::SwapWith(target) => {
+                observer.announce(&format!("Bot {player_idx} swaps hands with Bot {target}."));
+            }
+            _ => {}
+        }
+        chosen
+    }
+}
+
+/// Deal the cards this bot can't see (the full deck minus its own hand and
+/// the discard pile) randomly into opponent hands of their real sizes and onto
+/// the draw pile, producing one plausible full-information world.
+fn determinize(game: &GameState, player_idx: usize, rng: &mut ThreadRng) -> GameState {
+    let mut pool = full_deck();
+    for known in game.decks[player_idx].iter().chain(game.discard_pile.iter()) {
+        if let Some(pos) = pool.iter().position(|card| card == known) {
+            pool.remove(pos);
+        }
+    }
+    pool.shuffle(rng);
+
+    let mut determinized = game.clone();
+    for (idx, deck) in determinized.decks.iter_mut().enumerate() {
+        if idx == player_idx {
+            continue;
+        }
+        let hand_size = deck.len();
+        deck.clear();
+        for _ in 0..hand_size {
+            deck.push(pool.pop().unwrap());
+        }
+    }
+    determinized.draw_pile = pool;
+    determinized
+}
+
+/// Play a determinized state out to completion with every seat using the
+/// greedy policy, returning the winner's index (or `None` on starvation).
+fn rollout(mut game: GameState, rng: &mut ThreadRng) -> Option<usize> {
+    loop {
+        let cur_idx = game.cur_idx;
+        let moves = game.legal_moves(cur_idx);
+        let mv = greedy_move(&game, cur_idx, &moves, rng);
+        match game.apply(mv, rng) {
+            PlayResult::Win => return Some(cur_idx),
+            PlayResult::Starvation => return None,
+            PlayResult::NoPlace | PlayResult::Place => {}
+        }
+        if !game.awaiting_followup() {
+            game.advance_turn();
+        }
+    }
+}
+
+/// Fast, non-searching policy: play a matching card, preferring action or
+/// wild cards that hurt the next player, otherwise draw.
+fn greedy_move(game: &GameState, player_idx: usize, moves: &[Move], rng: &mut ThreadRng) -> Move {
+    if moves.len() == 1 {
+        return moves[0];
+    }
+
+    if matches!(moves[0], Move::ChooseWild(_)) {
+        return *moves
+            .iter()
+            .max_by_key(|mv| match mv {
+                Move::ChooseWild(color) => game.decks[player_idx]
+                    .iter()
+                    .filter(|card| card.color() == Some(*color))
+                    .count(),
+                _ => 0,
+            })
+            .unwrap();
+    }
+
+    moves
+        .iter()
+        .filter(|mv| matches!(mv, Move::PlayCard(_)))
+        .max_by_key(|mv| {
+            let Move::PlayCard(idx) = mv else {
+                unreachable!()
+            };
+            aggression(&game.decks[player_idx][*idx])
+        })
+        .copied()
+        .unwrap_or_else(|| {
+            // No matching card to play: draw rather than gamble on a
+            // Draw Four challenge, matching the stated "else draw" policy.
+            moves
+                .iter()
+                .find(|mv| matches!(mv, Move::DrawThenPlay))
+                .copied()
+                .unwrap_or_else(|| *moves.choose(rng).unwrap())
+        })
+}
+
+/// How much a card hurts the next player if played now.
+fn aggression(card: &Card) -> u8 {
+    match card {
+        Card::Wild(WildAction::Draw4) => 3,
+        Card::Action {
+            action: Action::Draw2,
+            ..
+        } => 2,
+        Card::Action { .. } | Card::Wild(WildAction::ChangeColor) => 1,
+        Card::Number { .. } => 0,
+    }
+}
+
+/// Among equally-winning moves, prefer holding onto wilds and dumping
+/// high-point cards (which cost more if stuck holding them) early.
+fn tie_break_score(game: &GameState, player_idx: usize, mv: Move) -> i32 {
+    let Move::PlayCard(idx) = mv else {
+        return 0;
+    };
+    let card = &game.decks[player_idx][idx];
+    let points = match card {
+        Card::Number { number, .. } => i32::from(*number),
+        Card::Action { .. } => 20,
+        Card::Wild(_) => 50,
+    };
+    let holding_wild_penalty = if matches!(card, Card::Wild(_)) { 100 } else { 0 };
+    points - holding_wild_penalty
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::Color;
+    use crate::rules::Rules;
+
+    fn number(number: u8, color: Color) -> Card {
+        Card::Number { number, color }
+    }
+
+    fn draw2(color: Color) -> Card {
+        Card::Action {
+            action: Action::Draw2,
+            color,
+        }
+    }
+
+    /// A two-seat game whose dealt hands don't matter; only `hand` (seat 0's
+    /// real hand, for the policy under test) does.
+    fn state_with_hand(hand: Vec<Card>) -> GameState {
+        let mut rng = rand::thread_rng();
+        let mut game = GameState::new(1, &mut rng, Rules::default());
+        game.decks[0] = hand;
+        game
+    }
+
+    #[test]
+    fn greedy_move_prefers_the_more_aggressive_matching_card() {
+        let game = state_with_hand(vec![number(5, Color::Red), draw2(Color::Red)]);
+        let moves = [Move::PlayCard(0), Move::PlayCard(1)];
+        let mut rng = rand::thread_rng();
+
+        let chosen = greedy_move(&game, 0, &moves, &mut rng);
+
+        assert!(
+            matches!(chosen, Move::PlayCard(1)),
+            "the Draw2 hurts the next player more than a plain number card"
+        );
+    }
+
+    #[test]
+    fn greedy_move_draws_rather_than_risk_a_draw4_challenge() {
+        let game = state_with_hand(vec![number(5, Color::Red)]);
+        let moves = [Move::ChallengeDraw4, Move::DrawThenPlay];
+        let mut rng = rand::thread_rng();
+
+        let chosen = greedy_move(&game, 0, &moves, &mut rng);
+
+        assert!(matches!(chosen, Move::DrawThenPlay));
+    }
+
+    #[test]
+    fn greedy_move_picks_the_wild_color_it_holds_the_most_of() {
+        let game = state_with_hand(vec![
+            number(1, Color::Blue),
+            number(2, Color::Blue),
+            number(3, Color::Red),
+        ]);
+        let moves = [
+            Move::ChooseWild(Color::Red),
+            Move::ChooseWild(Color::Blue),
+            Move::ChooseWild(Color::Green),
+            Move::ChooseWild(Color::Yellow),
+        ];
+        let mut rng = rand::thread_rng();
+
+        let chosen = greedy_move(&game, 0, &moves, &mut rng);
+
+        assert!(matches!(chosen, Move::ChooseWild(Color::Blue)));
+    }
+
+    #[test]
+    fn aggression_ranks_draw4_above_draw2_above_a_plain_card() {
+        assert!(aggression(&Card::Wild(WildAction::Draw4)) > aggression(&draw2(Color::Red)));
+        assert!(aggression(&draw2(Color::Red)) > aggression(&number(5, Color::Red)));
+    }
+}
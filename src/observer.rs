@@ -0,0 +1,24 @@
+use core::time;
+use std::thread;
+
+/// Sink for the events a turn produces. The interactive console game
+/// reports through here; headless self-play uses a no-op implementation
+/// so simulating thousands of games doesn't print or sleep at all.
+pub trait Observer {
+    fn announce(&mut self, message: &str);
+}
+
+pub struct ConsoleObserver;
+
+impl Observer for ConsoleObserver {
+    fn announce(&mut self, message: &str) {
+        println!("{message}");
+        thread::sleep(time::Duration::from_millis(500));
+    }
+}
+
+pub struct NullObserver;
+
+impl Observer for NullObserver {
+    fn announce(&mut self, _message: &str) {}
+}
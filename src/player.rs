@@ -0,0 +1,78 @@
+use rand::seq::SliceRandom;
+
+use crate::card::{Action, Card, WildAction};
+use crate::game::{GameState, Move};
+use crate::observer::Observer;
+
+/// Something that can choose a move for a given player index. The engine
+/// drives humans, random bots and search-based bots identically through
+/// this trait; `observer` is where they report what they did. Decision
+/// randomness (if any) is each implementation's own business, never the
+/// engine's, so that a logged move stream can be replayed without
+/// re-running any player's choice.
+pub trait Player {
+    fn choose_move(
+        &mut self,
+        game: &GameState,
+        player_idx: usize,
+        moves: &[Move],
+        observer: &mut dyn Observer,
+    ) -> Move;
+}
+
+pub struct Bot;
+
+impl Player for Bot {
+    fn choose_move(
+        &mut self,
+        game: &GameState,
+        player_idx: usize,
+        moves: &[Move],
+        observer: &mut dyn Observer,
+    ) -> Move {
+        let chosen = *moves.choose(&mut rand::thread_rng()).unwrap();
+        match chosen {
+            Move::PlayCard(idx) => {
+                let card = &game.decks[player_idx][idx];
+                observer.announce(&format!("Bot {player_idx} plays a {card}."));
+            }
+            Move::DrawThenPlay => {
+                if game.is_hot {
+                    match game.top_card() {
+                        Card::Action {
+                            action: Action::Draw2,
+                            ..
+                        } => observer.announce(&format!("Bot {player_idx} draws two cards.")),
+                        Card::Wild(WildAction::Draw4) => {
+                            observer.announce(&format!("Bot {player_idx} draws four cards."));
+                        }
+                        _ => {}
+                    }
+                } else {
+                    observer.announce(&format!("Bot {player_idx} draws a card."));
+                }
+            }
+            Move::ChooseWild(color) => {
+                observer.announce(&format!(
+                    "Bot {player_idx} chooses {color} as the new colour."
+                ));
+            }
+            Move::PlayDrawn => {
+                let card = game.decks[player_idx].last().unwrap();
+                observer.announce(&format!("Bot {player_idx} plays the card it just drew, a {card}."));
+            }
+            Move::ChallengeDraw4 => {
+                observer.announce(&format!("Bot {player_idx} challenges the Wild Draw Four."));
+            }
+            Move::SwapWith(target) => {
+                observer.announce(&format!("Bot {player_idx} swaps hands with Bot {target}."));
+            }
+            Move::JumpIn(idx) => {
+                let card = &game.decks[player_idx][idx];
+                observer.announce(&format!("Bot {player_idx} jumps in with a {card}."));
+            }
+            Move::Pass => {}
+        }
+        chosen
+    }
+}
@@ -0,0 +1,87 @@
+use rand::Rng;
+
+use crate::game::{GameState, Move, PlayResult};
+use crate::log::LoggedMove;
+use crate::observer::Observer;
+use crate::player::Player;
+
+/// How a single match played out: who finished on turn, how it ended, and
+/// how many turns it took. Used both to report an interactive game and to
+/// collect statistics across many headless ones.
+pub struct RoundOutcome {
+    pub result: PlayResult,
+    pub last_player: usize,
+    pub turns: u32,
+}
+
+/// Drive `game` to completion, asking whichever player is on turn for a
+/// move and reporting what happens through `observer`. Every move actually
+/// applied, in order, is appended to `log` so the caller can persist a
+/// replayable record of the match; pass a throwaway `Vec` to ignore it.
+pub fn play_round<R: Rng + ?Sized>(
+    game: &mut GameState,
+    players: &mut [Box<dyn Player>],
+    rng: &mut R,
+    observer: &mut dyn Observer,
+    log: &mut Vec<LoggedMove>,
+) -> RoundOutcome {
+    let mut turns = 0;
+
+    let result = loop {
+        if game.rules.jump_in && !game.awaiting_followup() {
+            if let Some((seat, idx)) = poll_jump_in(game, players, observer) {
+                log.push(LoggedMove {
+                    player: seat,
+                    mv: Move::JumpIn(idx),
+                });
+                let result = game.jump_in(seat, idx);
+                turns += 1;
+
+                if matches!(result, PlayResult::Win | PlayResult::Starvation) {
+                    break result;
+                }
+                if !game.awaiting_followup() {
+                    game.advance_turn();
+                }
+                continue;
+            }
+        }
+
+        let cur_idx = game.cur_idx;
+        let moves = game.legal_moves(cur_idx);
+        let mv = players[cur_idx].choose_move(game, cur_idx, &moves, observer);
+        log.push(LoggedMove { player: cur_idx, mv });
+        let result = game.apply(mv, rng);
+        turns += 1;
+
+        if matches!(result, PlayResult::Win | PlayResult::Starvation) {
+            break result;
+        }
+        if !game.awaiting_followup() {
+            game.advance_turn();
+        }
+    };
+
+    RoundOutcome {
+        result,
+        last_player: game.cur_idx,
+        turns,
+    }
+}
+
+/// Ask each seat (other than the one on turn) holding a card identical to
+/// the top of the discard pile, in seat order, whether they want to
+/// interrupt; the first to accept jumps in.
+fn poll_jump_in(
+    game: &GameState,
+    players: &mut [Box<dyn Player>],
+    observer: &mut dyn Observer,
+) -> Option<(usize, usize)> {
+    for (seat, idx) in game.jump_in_candidates(game.cur_idx) {
+        let moves = [Move::JumpIn(idx), Move::Pass];
+        if matches!(players[seat].choose_move(game, seat, &moves, observer), Move::JumpIn(_)) {
+            return Some((seat, idx));
+        }
+    }
+    None
+}
@@ -0,0 +1,36 @@
+use clap::Args;
+use serde::{Deserialize, Serialize};
+
+/// Optional house rules layered on top of classic UNO. Every field
+/// defaults to `false` (classic rules) and can be toggled independently,
+/// either via these CLI flags or by constructing the struct directly.
+#[derive(Args, Clone, Copy, Debug, Default, Serialize, Deserialize)]
+#[allow(clippy::struct_excessive_bools)] // each flag is an independent, unrelated toggle
+pub struct Rules {
+    /// Stack a Draw Two on a Draw Two, or a Wild Draw Four on a Wild Draw
+    /// Four, accumulating a pending draw instead of resolving it
+    /// immediately.
+    #[arg(long)]
+    pub stacking: bool,
+
+    /// Let the player targeted by a Wild Draw Four challenge it instead of
+    /// drawing; if the player who played it was holding a card matching
+    /// the color in play beforehand, they draw the penalty instead.
+    #[arg(long)]
+    pub draw4_challenge: bool,
+
+    /// Force a playable drawn card to be played immediately, instead of
+    /// letting the player choose to keep it.
+    #[arg(long)]
+    pub must_play_drawn: bool,
+
+    /// Play a 7 to swap hands with a chosen opponent, or a 0 to rotate
+    /// every hand one seat in the direction of play.
+    #[arg(long)]
+    pub seven_zero: bool,
+
+    /// Let any player holding a card identical to the top of the discard
+    /// pile interrupt and play it out of turn.
+    #[arg(long)]
+    pub jump_in: bool,
+}
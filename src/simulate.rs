@@ -0,0 +1,96 @@
+use rayon::prelude::*;
+
+use crate::game::{GameState, PlayResult};
+use crate::mc_bot::McBot;
+use crate::observer::NullObserver;
+use crate::player::{Bot, Player};
+use crate::round::play_round;
+use crate::rules::Rules;
+
+/// Seats after seat 0 in a simulated game (so 4 seats in all, numbered
+/// 0..=3): even seats play random, odd seats play the
+/// Monte-Carlo bot, so every game is a head-to-head between the two.
+const SIM_OPPONENT_COUNT: u8 = 3;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Strategy {
+    Random,
+    MonteCarlo,
+}
+
+impl Strategy {
+    fn for_seat(seat: usize) -> Strategy {
+        if seat.is_multiple_of(2) {
+            Strategy::Random
+        } else {
+            Strategy::MonteCarlo
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Strategy::Random => "random",
+            Strategy::MonteCarlo => "monte-carlo",
+        }
+    }
+
+    fn new_player(self) -> Box<dyn Player> {
+        match self {
+            Strategy::Random => Box::new(Bot),
+            Strategy::MonteCarlo => Box::new(McBot::default()),
+        }
+    }
+}
+
+struct GameStats {
+    winner: Option<Strategy>,
+    turns: u32,
+    starved: bool,
+}
+
+fn simulate_one(rules: Rules) -> GameStats {
+    let mut rng = rand::thread_rng();
+    let mut game = GameState::new(SIM_OPPONENT_COUNT, &mut rng, rules);
+    let mut players: Vec<Box<dyn Player>> = (0..=SIM_OPPONENT_COUNT)
+        .map(|seat| Strategy::for_seat(usize::from(seat)).new_player())
+        .collect();
+    let mut observer = NullObserver;
+
+    let outcome = play_round(&mut game, &mut players, &mut rng, &mut observer, &mut Vec::new());
+
+    GameStats {
+        winner: matches!(outcome.result, PlayResult::Win)
+            .then(|| Strategy::for_seat(outcome.last_player)),
+        turns: outcome.turns,
+        starved: matches!(outcome.result, PlayResult::Starvation),
+    }
+}
+
+/// Run `games` headless self-play matches across every core and report
+/// per-strategy win rates, average game length, and starvation frequency.
+#[allow(clippy::cast_precision_loss)]
+pub fn run(games: u32, rules: Rules) {
+    let results: Vec<GameStats> = (0..games)
+        .into_par_iter()
+        .map(|_| simulate_one(rules))
+        .collect();
+
+    let total = results.len() as f64;
+    let starved = results.iter().filter(|r| r.starved).count();
+    let avg_turns = results.iter().map(|r| f64::from(r.turns)).sum::<f64>() / total;
+
+    println!("Simulated {games} games:");
+    for strategy in [Strategy::Random, Strategy::MonteCarlo] {
+        let wins = results.iter().filter(|r| r.winner == Some(strategy)).count();
+        println!(
+            "  {} win rate:  {:.1}%",
+            strategy.label(),
+            100.0 * wins as f64 / total
+        );
+    }
+    println!("  average game length: {avg_turns:.1} turns");
+    println!(
+        "  starvation frequency: {:.1}%",
+        100.0 * starved as f64 / total
+    );
+}